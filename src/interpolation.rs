@@ -5,46 +5,189 @@ pub trait Interpolate {
     fn interpolate(&self, other: &Self, t: f32) -> Self;
 }
 
+/// Projects a component forward using the implied velocity between the two
+/// most recent snapshots, for when the render timeline outruns the buffer.
+pub trait Extrapolate {
+    fn extrapolate(&self, previous: &Self, t: f32) -> Self;
+}
+
 #[derive(Component, Default)]
 pub struct InterpolatedReplication;
 
-/// Interpolate between snapshots.
+/// Opt-in companion marker for `InterpolatedReplication`: when the snapshot
+/// buffer starves, the entity keeps moving via dead reckoning instead of
+/// freezing on the last snapshot.
+#[derive(Component, Default)]
+pub struct ExtrapolatedReplication;
+
+/// Shared interpolation configuration, built from the tick rate supplied to
+/// `RepliconSnapPlugin`.
+#[derive(Resource, Clone, Copy)]
+pub struct InterpolationConfig {
+    max_tick_rate: u16,
+    network_tick_delta: f32,
+    interpolation_delay_ticks: u16,
+    max_extrapolation_ticks: u16
+}
+
+impl InterpolationConfig {
+    #[inline]
+    pub fn new(
+        max_tick_rate: u16,
+        interpolation_delay_ticks: u16,
+        max_extrapolation_ticks: u16
+    ) -> Self {
+        Self {
+            max_tick_rate,
+            network_tick_delta: 1.0 / max_tick_rate as f32,
+            interpolation_delay_ticks,
+            max_extrapolation_ticks
+        }
+    }
+
+    #[inline]
+    pub fn max_tick_rate(&self) -> u16 {
+        self.max_tick_rate
+    }
+
+    #[inline]
+    pub fn network_tick_delta(&self) -> f32 {
+        self.network_tick_delta
+    }
+
+    #[inline]
+    pub fn interpolation_delay_ticks(&self) -> u16 {
+        self.interpolation_delay_ticks
+    }
+
+    #[inline]
+    pub fn max_extrapolation_ticks(&self) -> u16 {
+        self.max_extrapolation_ticks
+    }
+}
+
+/// How many snapshots a freshly-initialized `ComponentSnapshotBuffer` holds.
+pub(crate) const DEFAULT_SNAPSHOT_BUFFER_SIZE: usize = 64;
+
+/// Automatically inserts an empty `ComponentSnapshotBuffer<C>` once both the
+/// component and either the `InterpolatedReplication` or
+/// `ExtrapolatedReplication` marker are present. This spares callers from
+/// manually inserting the buffer on every replicated entity. The buffer is
+/// left unseeded so its render clock gets anchored to the entity's real
+/// current tick by the first snapshot `client_populate_component_buffer`
+/// inserts, rather than pinned to tick `0` and left to count up from there.
+pub(crate) fn snapshot_buffer_init_system<C: Component>(
+    q: Query<
+        Entity,
+        (
+            Or<(
+                Added<InterpolatedReplication>,
+                Added<ExtrapolatedReplication>,
+                Added<C>
+            )>,
+            Or<(With<InterpolatedReplication>, With<ExtrapolatedReplication>)>,
+            With<C>,
+            Without<ComponentSnapshotBuffer<C>>
+        )
+    >,
+    mut commands: Commands,
+) {
+    for e in q.iter() {
+        commands.entity(e).insert(ComponentSnapshotBuffer::<C>::with_capacity(DEFAULT_SNAPSHOT_BUFFER_SIZE));
+    }
+}
+
+/// Interpolate between snapshots, rendering on a delayed timeline instead of
+/// snapping to whatever snapshot last arrived. The buffer's own fractional
+/// tick clock is pushed back by `interpolation_delay_ticks`, then the two
+/// snapshots whose ticks bracket that point are blended using the actual
+/// tick spacing between them, so dropped or reordered packets and jittery
+/// arrival times don't produce visible snaps.
 pub fn interpolate<C: Component + Interpolate>(
     component: &mut C,
     snapshot_buffer: &ComponentSnapshotBuffer<C>,
-    delta_time: f32,
-    network_tick_delta: f32
+    interpolation_config: &InterpolationConfig
 ) {
-    let buff_len =  snapshot_buffer.len();
+    let buff_len = snapshot_buffer.len();
     if buff_len < 2 {
         return;
     }
 
-    // network_tick_delta = 100%
-    // calc elapsed = ?%
-    // into 0.0 ~ 1.0
+    let render_tick = snapshot_buffer.render_tick()
+        - interpolation_config.interpolation_delay_ticks() as f32;
+
+    let mut iter = snapshot_buffer.iter();
+    let mut older = iter.next().unwrap(); //buffer is longer than 2
+    let mut newer = iter.next().unwrap();
+    for snapshot in iter {
+        if render_tick <= newer.tick() as f32 {
+            break;
+        }
+        older = newer;
+        newer = snapshot;
+    }
 
-    let elapsed = snapshot_buffer.age();
-    if elapsed > network_tick_delta + delta_time {
-        debug!("discarding interpolation for old snapshot... elapsed: {elapsed}");
+    let span = (newer.tick() as f32 - older.tick() as f32).max(f32::EPSILON);
+    let t = ((render_tick - older.tick() as f32) / span).clamp(0.0, 1.0);
+
+    info!("performing interpolation at t: {t}, render_tick: {render_tick}");
+    *component = older.component().interpolate(newer.component(), t);
+}
+
+/// Like `interpolate`, but once the render timeline runs past the newest
+/// snapshot it keeps dead-reckoning forward from the implied velocity
+/// between the last two snapshots, instead of freezing. Extrapolation is
+/// clamped to `max_extrapolation_ticks`; it snaps back to ordinary
+/// interpolation as soon as a fresher snapshot brings the render timeline
+/// back within the buffer.
+pub fn interpolate_or_extrapolate<C: Component + Interpolate + Extrapolate>(
+    component: &mut C,
+    snapshot_buffer: &ComponentSnapshotBuffer<C>,
+    interpolation_config: &InterpolationConfig
+) {
+    let buff_len = snapshot_buffer.len();
+    if buff_len < 2 {
         return;
     }
-    let t = (elapsed / network_tick_delta).clamp(0.0, 1.0);
 
-    let mut iter = snapshot_buffer.iter().rev();
-    let latest = iter.next().unwrap(); //buffer is longer than 2
-    let second = iter.next().unwrap();
+    let render_tick = snapshot_buffer.render_tick()
+        - interpolation_config.interpolation_delay_ticks() as f32;
+
+    let mut iter = snapshot_buffer.iter();
+    let mut older = iter.next().unwrap(); //buffer is longer than 2
+    let mut newer = iter.next().unwrap();
+    for snapshot in iter {
+        if render_tick <= newer.tick() as f32 {
+            break;
+        }
+        older = newer;
+        newer = snapshot;
+    }
+
+    let span = (newer.tick() as f32 - older.tick() as f32).max(f32::EPSILON);
+    let t = (render_tick - older.tick() as f32) / span;
+
+    if t <= 1.0 {
+        *component = older.component().interpolate(newer.component(), t.max(0.0));
+        return;
+    }
 
-    info!("performing interpolare at t: {t}");
-    *component = second.component().interpolate(latest.component(), t);
+    let max_t = 1.0 + interpolation_config.max_extrapolation_ticks() as f32 / span;
+    let t = t.min(max_t);
+    debug!("extrapolating past newest snapshot, t: {t}, render_tick: {render_tick}");
+    *component = newer.component().extrapolate(older.component(), t);
 }
 
-/// Advances the snapshot buffer time for entities.
-pub(crate) fn add_snapshots_age_system<C: Component>(
+/// Advances every interpolated buffer's render clock, independent of when
+/// snapshots actually arrive.
+pub(crate) fn advance_render_tick_system<C: Component>(
     mut q: Query<&mut ComponentSnapshotBuffer<C>>,
     time: Res<Time>,
+    interpolation_config: Res<InterpolationConfig>
 ) {
+    let delta_time = time.delta_seconds();
+    let network_tick_delta = interpolation_config.network_tick_delta();
     for mut snapshot_buffer in q.iter_mut() {
-        snapshot_buffer.add_age(time.delta_seconds());
+        snapshot_buffer.advance_render_tick(delta_time, network_tick_delta);
     }
 }
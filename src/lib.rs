@@ -8,7 +8,7 @@ pub mod prelude {
         core::*,
         prediction::*,
         interpolation::*,
-        snapshots::{*, component_snapshots::*, event_snapshots::*}
+        snapshots::{*, component_snapshots::*, event_snapshots::*, delta_component_snapshots::*}
     };
 }
 
@@ -27,11 +27,20 @@ pub enum SnapSet {
     ServerOnSend,
 }
 
-pub struct RepliconSnapPlugin;
+pub struct RepliconSnapPlugin {
+    pub max_tick_rate: u16,
+    pub interpolation_delay_ticks: u16,
+    pub max_extrapolation_ticks: u16
+}
 
 impl Plugin for RepliconSnapPlugin {
     fn build(&self, app: &mut App) {
         app
+        .insert_resource(InterpolationConfig::new(
+            self.max_tick_rate,
+            self.interpolation_delay_ticks,
+            self.max_extrapolation_ticks
+        ))
         .configure_sets(
             PreUpdate, 
             SnapSet::ClientOnRecv.after(ClientSet::Receive)
@@ -63,7 +72,20 @@ pub trait RepliconSnapAppExt {
     fn use_component_snapshot<C>(
         &mut self
     ) -> &mut Self
-    where C: Component + Serialize + DeserializeOwned + Clone; 
+    where C: Component + Serialize + DeserializeOwned + Clone;
+
+    fn use_predicted_component<C, I>(
+        &mut self,
+        tolerance: f32
+    ) -> &mut Self
+    where
+        C: Component + Predict<I> + PredictionError + Serialize + DeserializeOwned + Clone,
+        I: IndexedEvent + Serialize + DeserializeOwned + Clone;
+
+    fn use_delta_component_snapshot<C>(
+        &mut self
+    ) -> &mut Self
+    where C: Component + DeltaSnapshot + Serialize + DeserializeOwned + Clone;
 }
 
 impl RepliconSnapAppExt for App {
@@ -106,9 +128,63 @@ impl RepliconSnapAppExt for App {
         if self.world.contains_resource::<RepliconClient>() {
             self.add_systems(
                 PreUpdate, (
+                    snapshot_buffer_init_system::<C>,
                     client_populate_component_buffer::<C>,
-                    add_snapshots_age_system::<C>
+                    advance_render_tick_system::<C>
+                )
+                .chain()
+                .in_set(SnapSet::ClientOnRecv)
+            );
+        }
+        self
+    }
+
+    fn use_predicted_component<C, I>(
+        &mut self,
+        tolerance: f32
+    ) -> &mut Self
+    where
+        C: Component + Predict<I> + PredictionError + Serialize + DeserializeOwned + Clone,
+        I: IndexedEvent + Serialize + DeserializeOwned + Clone {
+        if self.world.contains_resource::<RepliconClient>() {
+            self
+            .insert_resource(PredictionTolerance::<C>::new(tolerance))
+            .add_systems(
+                PreUpdate,
+                reconcile_predicted_component_system::<C, I>
+                .in_set(SnapSet::ClientOnRecv)
+            )
+            .add_systems(
+                PostUpdate, (
+                    predicted_buffer_init_system::<C>,
+                    predict_component_system::<C, I>
+                )
+                .chain()
+                .after(client_populate_client_event_buffer::<I>)
+                .in_set(SnapSet::ClientOnUpdate)
+            );
+        }
+        self
+    }
+
+    fn use_delta_component_snapshot<C>(
+        &mut self
+    ) -> &mut Self
+    where C: Component + DeltaSnapshot + Serialize + DeserializeOwned + Clone {
+        if self.world.contains_resource::<RepliconServer>() {
+            self.add_systems(
+                PostUpdate,
+                server_populate_delta_component_buffer::<C>
+                .in_set(SnapSet::ServerOnSend)
+            );
+        }
+        if self.world.contains_resource::<RepliconClient>() {
+            self.add_systems(
+                PreUpdate, (
+                    delta_snapshot_buffer_init_system::<C>,
+                    client_populate_delta_component_buffer::<C>
                 )
+                .chain()
                 .in_set(SnapSet::ClientOnRecv)
             );
         }
@@ -1,6 +1,12 @@
+use std::{collections::VecDeque, marker::PhantomData};
 use bevy::prelude::*;
+use bevy_replicon::client::ServerEntityTicks;
 use bevy_replicon_renet::renet::transport::NetcodeClientTransport;
-use crate::NetworkOwner;
+use crate::{
+    NetworkOwner,
+    interpolation::InterpolationConfig,
+    snapshots::event_snapshots::{EventSnapshotBuffer, IndexedEvent}
+};
 
 #[derive(Component, Default)]
 pub struct ClientPrediction;
@@ -8,6 +14,101 @@ pub struct ClientPrediction;
 #[derive(Component)]
 pub struct OwnerControlling;
 
+/// Tracks the last server tick that has already been reconciled for an
+/// `OwnerControlling` entity, so a tick is never replayed against twice.
+#[derive(Component, Default)]
+pub(crate) struct LastReconciledTick(u32);
+
+/// Applies a locally-generated input to a predicted component.
+pub trait Predict<I> {
+    fn predict(&mut self, input: &I, dt: f32);
+}
+
+/// Measures how far a predicted value has drifted from the authoritative
+/// one, so reconciliation can decide whether a correction is worth it.
+pub trait PredictionError {
+    fn prediction_error(&self, authoritative: &Self) -> f32;
+}
+
+#[derive(Resource)]
+pub(crate) struct PredictionTolerance<C> {
+    value: f32,
+    _marker: PhantomData<C>
+}
+
+impl<C> PredictionTolerance<C> {
+    #[inline]
+    pub(crate) fn new(value: f32) -> Self {
+        Self {
+            value,
+            _marker: PhantomData
+        }
+    }
+}
+
+/// How many predicted states a freshly-initialized `PredictedComponentBuffer`
+/// holds.
+pub(crate) const DEFAULT_PREDICTED_BUFFER_SIZE: usize = 64;
+
+/// One predicted state of `C`, keyed by the index of the input that produced
+/// it rather than a server tick.
+pub struct PredictedSnapshot<C> {
+    index: u32,
+    component: C
+}
+
+impl<C> PredictedSnapshot<C> {
+    #[inline]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    #[inline]
+    pub fn component(&self) -> &C {
+        &self.component
+    }
+}
+
+/// Local prediction history for an `OwnerControlling` entity, keyed by input
+/// index. This is deliberately its own buffer rather than
+/// `ComponentSnapshotBuffer<C>`: that buffer is keyed by server tick and is
+/// shared with `client_populate_component_buffer`, whose entries would be
+/// silently discarded as "old" the moment a locally-ahead predicted index
+/// landed in the same monotonic `insert()` sequence.
+#[derive(Component)]
+pub struct PredictedComponentBuffer<C> {
+    buffer: VecDeque<PredictedSnapshot<C>>,
+    max_buffer_size: usize
+}
+
+impl<C> PredictedComponentBuffer<C> {
+    #[inline]
+    pub fn with_capacity(max_buffer_size: usize) -> Self {
+        Self {
+            buffer: VecDeque::with_capacity(max_buffer_size),
+            max_buffer_size
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, component: C, index: u32) {
+        if self.max_buffer_size == 0 {
+            return;
+        }
+
+        if self.buffer.len() >= self.max_buffer_size {
+            self.buffer.pop_front();
+        }
+
+        self.buffer.push_back(PredictedSnapshot { index, component });
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &PredictedSnapshot<C>> {
+        self.buffer.iter()
+    }
+}
+
 pub(crate) fn init_prediction(
     q: Query<(Entity, &NetworkOwner), Added<ClientPrediction>>,
     transport: Res<NetcodeClientTransport>,
@@ -15,7 +116,116 @@ pub(crate) fn init_prediction(
 ) {
     for (e, o) in q.iter() {
         if o.get() == transport.client_id().raw() {
-            commands.entity(e).insert(OwnerControlling);
+            commands.entity(e)
+            .insert(OwnerControlling)
+            .insert(LastReconciledTick::default());
+        }
+    }
+}
+
+/// Automatically inserts an empty `PredictedComponentBuffer<C>` once both the
+/// component and `OwnerControlling` are present.
+pub(crate) fn predicted_buffer_init_system<C: Component>(
+    q: Query<
+        Entity,
+        (
+            Or<(Added<OwnerControlling>, Added<C>)>,
+            With<C>,
+            Without<PredictedComponentBuffer<C>>
+        )
+    >,
+    mut commands: Commands,
+) {
+    for e in q.iter() {
+        commands.entity(e).insert(PredictedComponentBuffer::<C>::with_capacity(DEFAULT_PREDICTED_BUFFER_SIZE));
+    }
+}
+
+/// Applies every input produced since the last run to the owner's component,
+/// immediately advancing it ahead of the server, and records the resulting
+/// state in `PredictedComponentBuffer` keyed by the input's own index. Each
+/// input is applied with a fixed per-tick timestep rather than the current
+/// frame's delta, so replaying the same inputs during reconciliation
+/// reproduces exactly the trajectory local prediction originally produced.
+/// Inputs are expected to be produced and consumed in index order, so the
+/// buffer ends up with exactly one predicted snapshot per predicted tick.
+pub(crate) fn predict_component_system<C, I>(
+    mut q: Query<
+        (&mut C, &mut PredictedComponentBuffer<C>, &mut EventSnapshotBuffer<I>),
+        With<OwnerControlling>
+    >,
+    interpolation_config: Res<InterpolationConfig>,
+)
+where
+    C: Component + Predict<I> + Clone,
+    I: IndexedEvent + Clone
+{
+    let dt = interpolation_config.network_tick_delta();
+    for (mut c, mut predicted_buff, mut input_buff) in q.iter_mut() {
+        for input in input_buff.frontier() {
+            c.predict(input.event(), dt);
+            predicted_buff.insert(c.clone(), input.index() as u32);
+        }
+    }
+}
+
+/// Compares the authoritative value replicon just wrote to `C` against the
+/// predicted snapshot stored for the same tick. If the two have drifted
+/// beyond tolerance, the entity is already holding the server's value (it
+/// was overwritten by replication before this system runs), so rolling
+/// forward only requires replaying every input with an index past the
+/// reconciled tick, each with the same fixed per-tick timestep used to
+/// produce it originally.
+pub(crate) fn reconcile_predicted_component_system<C, I>(
+    mut q: Query<
+        (
+            Entity,
+            &mut C,
+            &PredictedComponentBuffer<C>,
+            &EventSnapshotBuffer<I>,
+            &mut LastReconciledTick
+        ),
+        With<OwnerControlling>
+    >,
+    server_ticks: Res<ServerEntityTicks>,
+    tolerance: Res<PredictionTolerance<C>>,
+    interpolation_config: Res<InterpolationConfig>,
+)
+where
+    C: Component + Predict<I> + PredictionError + Clone,
+    I: IndexedEvent + Clone
+{
+    let dt = interpolation_config.network_tick_delta();
+    for (e, mut c, predicted, input_buff, mut last_reconciled) in q.iter_mut() {
+        let Some(server_tick) = server_ticks.get(&e) else {
+            continue;
+        };
+        let server_tick = server_tick.get();
+        if server_tick <= last_reconciled.0 {
+            continue;
+        }
+        last_reconciled.0 = server_tick;
+
+        let needs_correction = match predicted.iter().find(|s| s.index() == server_tick) {
+            Some(predicted_at_tick) => {
+                predicted_at_tick.component().prediction_error(&c) > tolerance.value
+            }
+            None => true
+        };
+        if needs_correction {
+            debug!("reconciling predicted component for entity: {e:?} at tick: {server_tick}");
+        }
+
+        // `c` currently holds the server's value for `server_tick`, whether
+        // or not it needed a hard correction — either way it must be rolled
+        // forward to the present by replaying every input since confirmed,
+        // or the entity snaps back to its old server-tick position on every
+        // accurate tick instead of just the mispredicted ones.
+        for input in input_buff.iter() {
+            if input.index() as u32 <= server_tick {
+                continue;
+            }
+            c.predict(input.event(), dt);
         }
-    }    
+    }
 }
@@ -1,9 +1,14 @@
 pub mod component_snapshots;
 pub mod event_snapshots;
+pub mod delta_component_snapshots;
 
 use bevy::prelude::*;
 use bevy_replicon::{client::ServerEntityTicks, core::replicon_tick::RepliconTick, network_event::client_event::FromClient};
 use component_snapshots::ComponentSnapshotBuffer;
+use delta_component_snapshots::{
+    DeltaComponentSnapshotBuffer, DeltaSnapshot,
+    DEFAULT_DELTA_SNAPSHOT_BUFFER_SIZE, DEFAULT_DELTA_KEYFRAME_INTERVAL
+};
 use serde::{Serialize, de::DeserializeOwned};
 use crate::{EventSnapshotBuffer, EventSnapshotClientMap, IndexedEvent};
 
@@ -42,6 +47,62 @@ pub(crate) fn client_populate_component_buffer<C: Component + Clone>(
     }
 }
 
+pub(crate) fn server_populate_delta_component_buffer<C: Component + DeltaSnapshot + Clone>(
+    mut query: Query<
+        (&C, &mut DeltaComponentSnapshotBuffer<C>),
+        Or<(Added<C>, Changed<C>)>
+    >,
+    replicon_tick: Res<RepliconTick>
+) {
+    for (c, mut buff) in query.iter_mut() {
+        buff.insert(c.clone(), replicon_tick.get());
+    }
+}
+
+/// Automatically inserts an empty `DeltaComponentSnapshotBuffer<C>` once the
+/// component is present, so callers don't have to insert it manually on
+/// every replicated entity.
+pub(crate) fn delta_snapshot_buffer_init_system<C: Component + DeltaSnapshot + Clone>(
+    q: Query<
+        Entity,
+        (
+            Added<C>,
+            Without<DeltaComponentSnapshotBuffer<C>>
+        )
+    >,
+    mut commands: Commands,
+) {
+    for e in q.iter() {
+        commands.entity(e).insert(DeltaComponentSnapshotBuffer::<C>::with_capacity(
+            DEFAULT_DELTA_SNAPSHOT_BUFFER_SIZE,
+            DEFAULT_DELTA_KEYFRAME_INTERVAL
+        ));
+    }
+}
+
+pub(crate) fn client_populate_delta_component_buffer<C: Component + DeltaSnapshot + Clone>(
+    mut query: Query<
+        (Entity, &C, &mut DeltaComponentSnapshotBuffer<C>),
+        Or<(Added<C>, Changed<C>)>
+    >,
+    server_tick: Res<ServerEntityTicks>,
+) {
+    for (e, c, mut buff) in query.iter_mut() {
+        match server_tick.get(&e) {
+            Some(tick) => {
+                buff.insert(c.clone(), tick.get());
+            }
+            None => {
+                if cfg!(debug_assertions) {
+                    panic!("server tick is not mapped for this entity: {e:?}");
+                } else {
+                    warn!("server tick is not mapped for this entity: {e:?}, discarding...");
+                }
+            }
+        }
+    }
+}
+
 pub(crate) fn server_populate_client_event_buffer<E>(
     mut events: EventReader<FromClient<E>>,
     mut buffer: ResMut<EventSnapshotClientMap<E>>,
@@ -11,9 +11,9 @@ pub struct ComponentSnapshot<C: Component> {
 impl<C: Component> ComponentSnapshot<C> {
     #[inline]
     pub fn new(component: C, tick: u32) -> Self {
-        Self{ 
-            tick, 
-            component 
+        Self{
+            tick,
+            component
         }
     }
 
@@ -31,7 +31,7 @@ impl<C: Component> ComponentSnapshot<C> {
 #[derive(Component, Deserialize, Serialize)]
 pub struct ComponentSnapshotBuffer<C: Component> {
     buffer: VecDeque<ComponentSnapshot<C>>,
-    time_since_last_snapshot: f32,
+    render_tick: f32,
     latest_snapshot_tick: u32,
     max_buffer_size: usize
 }
@@ -41,7 +41,7 @@ impl<C: Component> ComponentSnapshotBuffer<C> {
     pub fn with_capacity(max_buffer_size: usize) -> Self {
         Self{
             buffer: VecDeque::with_capacity(max_buffer_size),
-            time_since_last_snapshot: 0.0,
+            render_tick: 0.0,
             latest_snapshot_tick: 0,
             max_buffer_size
         }
@@ -55,18 +55,22 @@ impl<C: Component> ComponentSnapshotBuffer<C> {
 
         if tick < self.latest_snapshot_tick {
             warn!(
-                "discarding a old component snapshot with tick:{}, latest:{}", 
+                "discarding a old component snapshot with tick:{}, latest:{}",
                 tick, self.latest_snapshot_tick
             );
             return;
         }
 
+        if self.buffer.is_empty() {
+            // anchor the render clock to the first snapshot this buffer ever sees
+            self.render_tick = tick as f32;
+        }
+
         if self.buffer.len() >= self.max_buffer_size {
             self.buffer.pop_front();
         }
 
         self.buffer.push_back(ComponentSnapshot::new(component, tick));
-        self.time_since_last_snapshot = 0.0;
         self.latest_snapshot_tick = tick;
     }
 
@@ -100,13 +104,16 @@ impl<C: Component> ComponentSnapshotBuffer<C> {
         self.buffer.iter()
     }
 
+    /// The buffer's own fractional tick clock. Advances every frame
+    /// regardless of snapshot arrival, so interpolation can be driven from a
+    /// smooth timeline instead of snapping to whatever just arrived.
     #[inline]
-    pub fn age(&self) -> f32 {
-        self.time_since_last_snapshot
+    pub fn render_tick(&self) -> f32 {
+        self.render_tick
     }
 
     #[inline]
-    pub(crate) fn add_age(&mut self, add: f32) {
-        self.time_since_last_snapshot += add;
+    pub(crate) fn advance_render_tick(&mut self, delta_time: f32, network_tick_delta: f32) {
+        self.render_tick += delta_time / network_tick_delta;
     }
 }
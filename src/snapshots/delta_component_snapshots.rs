@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
+
+/// A component that can be encoded as a diff against an earlier value of
+/// itself, so a snapshot history only has to carry the full value once per
+/// keyframe instead of every tick.
+pub trait DeltaSnapshot: Sized {
+    type Delta: Serialize + DeserializeOwned;
+
+    fn diff(&self, base: &Self) -> Self::Delta;
+    fn apply(&mut self, delta: &Self::Delta);
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "C: Serialize, C::Delta: Serialize",
+    deserialize = "C: DeserializeOwned, C::Delta: DeserializeOwned"
+))]
+enum DeltaComponentSnapshotEntry<C: DeltaSnapshot> {
+    Keyframe(C),
+    Delta(C::Delta),
+}
+
+/// A snapshot reconstructed by folding deltas forward from the most recent
+/// keyframe, shaped like `ComponentSnapshot` so it reads the same way.
+pub struct ReconstructedSnapshot<C> {
+    tick: u32,
+    component: C
+}
+
+impl<C> ReconstructedSnapshot<C> {
+    #[inline]
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    #[inline]
+    pub fn component(&self) -> &C {
+        &self.component
+    }
+}
+
+/// How many entries a freshly-initialized `DeltaComponentSnapshotBuffer`
+/// holds.
+pub(crate) const DEFAULT_DELTA_SNAPSHOT_BUFFER_SIZE: usize = 64;
+
+/// How many deltas a freshly-initialized `DeltaComponentSnapshotBuffer`
+/// stores between two full keyframe snapshots.
+pub(crate) const DEFAULT_DELTA_KEYFRAME_INTERVAL: u32 = 10;
+
+#[derive(Component, Deserialize, Serialize)]
+#[serde(bound(
+    serialize = "C: Serialize, C::Delta: Serialize",
+    deserialize = "C: DeserializeOwned, C::Delta: DeserializeOwned"
+))]
+pub struct DeltaComponentSnapshotBuffer<C: Component + DeltaSnapshot + Clone> {
+    buffer: VecDeque<(u32, DeltaComponentSnapshotEntry<C>)>,
+    keyframe_interval: u32,
+    ticks_since_keyframe: u32,
+    latest_value: Option<C>,
+    latest_snapshot_tick: u32,
+    max_buffer_size: usize
+}
+
+impl<C: Component + DeltaSnapshot + Clone> DeltaComponentSnapshotBuffer<C> {
+    /// `keyframe_interval` is how many deltas are stored between two full
+    /// keyframe snapshots.
+    #[inline]
+    pub fn with_capacity(max_buffer_size: usize, keyframe_interval: u32) -> Self {
+        Self {
+            buffer: VecDeque::with_capacity(max_buffer_size),
+            keyframe_interval,
+            ticks_since_keyframe: 0,
+            latest_value: None,
+            latest_snapshot_tick: 0,
+            max_buffer_size
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, component: C, tick: u32) {
+        if self.max_buffer_size == 0 {
+            return;
+        }
+
+        if !self.buffer.is_empty() && tick < self.latest_snapshot_tick {
+            warn!(
+                "discarding a old delta component snapshot with tick:{}, latest:{}",
+                tick, self.latest_snapshot_tick
+            );
+            return;
+        }
+
+        let entry = match &self.latest_value {
+            Some(base) if self.ticks_since_keyframe < self.keyframe_interval => {
+                self.ticks_since_keyframe += 1;
+                DeltaComponentSnapshotEntry::Delta(component.diff(base))
+            }
+            _ => {
+                self.ticks_since_keyframe = 0;
+                DeltaComponentSnapshotEntry::Keyframe(component.clone())
+            }
+        };
+
+        self.latest_value = Some(component);
+        self.latest_snapshot_tick = tick;
+        self.buffer.push_back((tick, entry));
+        self.evict_stale_keyframe_groups();
+    }
+
+    /// Trims whole keyframe groups (a `Keyframe` and every `Delta` up to the
+    /// next `Keyframe`) from the front while over capacity, so every
+    /// remaining entry always has its keyframe still in the buffer.
+    /// `max_buffer_size` is therefore a soft cap: if a single group (driven
+    /// by `keyframe_interval`) is larger than it, that group is kept intact
+    /// rather than leaving unreconstructable deltas behind.
+    fn evict_stale_keyframe_groups(&mut self) {
+        while self.buffer.len() > self.max_buffer_size {
+            let next_keyframe_offset = self.buffer.iter()
+                .skip(1)
+                .position(|(_, entry)| matches!(entry, DeltaComponentSnapshotEntry::Keyframe(_)));
+
+            match next_keyframe_offset {
+                Some(offset) => {
+                    for _ in 0..=offset {
+                        self.buffer.pop_front();
+                    }
+                }
+                None => break
+            }
+        }
+    }
+
+    #[inline]
+    pub fn latest_snapshot_tick(&self) -> u32 {
+        self.latest_snapshot_tick
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Reconstructs the full value at the latest tick by folding deltas
+    /// forward from the most recent keyframe.
+    #[inline]
+    pub fn latest_snapshot(&self) -> Option<ReconstructedSnapshot<C>> {
+        self.reconstruct(self.buffer.len().checked_sub(1)?)
+    }
+
+    /// Reconstructs every snapshot in the buffer, folding deltas forward
+    /// from each keyframe, in tick order.
+    pub fn iter(&self) -> impl Iterator<Item = ReconstructedSnapshot<C>> + '_ {
+        (0..self.buffer.len()).filter_map(|i| self.reconstruct(i))
+    }
+
+    fn reconstruct(&self, index: usize) -> Option<ReconstructedSnapshot<C>> {
+        let mut keyframe_index = index;
+        while keyframe_index > 0
+            && !matches!(self.buffer[keyframe_index].1, DeltaComponentSnapshotEntry::Keyframe(_)) {
+            keyframe_index -= 1;
+        }
+
+        let DeltaComponentSnapshotEntry::Keyframe(ref keyframe) = self.buffer.get(keyframe_index)?.1 else {
+            return None;
+        };
+
+        let mut component = keyframe.clone();
+        for (_, entry) in self.buffer.range(keyframe_index + 1..=index) {
+            if let DeltaComponentSnapshotEntry::Delta(delta) = entry {
+                component.apply(delta);
+            }
+        }
+
+        Some(ReconstructedSnapshot {
+            tick: self.buffer[index].0,
+            component
+        })
+    }
+}